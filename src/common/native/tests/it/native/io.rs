@@ -100,8 +100,8 @@ fn test_bitpacking() {
 fn test_deleta_bitpacking() {
     let size = WRITE_PAGE * 5;
     let chunk = vec![
-        UInt32Type::from_data((0..size as u32).collect()),
-        Int32Type::from_data((0..size as i32).collect()),
+        UInt32Type::from_data(0..size as u32),
+        Int32Type::from_data(0..size as i32),
     ];
     test_write_read(chunk);
 }
@@ -110,8 +110,8 @@ fn test_deleta_bitpacking() {
 fn test_onevalue() {
     let size = 10000;
     let chunk = vec![
-        BooleanType::from_data((0..size).map(|_| true).collect()),
-        BooleanType::from_data((0..size).map(|_| false).collect()),
+        BooleanType::from_data((0..size).map(|_| true)),
+        BooleanType::from_data((0..size).map(|_| false)),
         UInt32Type::from_data(vec![3; size]),
     ];
     test_write_read(chunk);
@@ -58,6 +58,7 @@ pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
 mod stage;
+pub use stage::expand_pattern_braces;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
 pub use stage::StageFileInfoStream;
@@ -29,6 +29,7 @@ use futures::Stream;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use opendal::EntryMode;
+use opendal::Lister;
 use opendal::Metadata;
 use opendal::Metakey;
 use opendal::Operator;
@@ -314,6 +315,73 @@ impl StageFilesInfo {
         }
     }
 
+    /// List the files at most `max_depth` directories below the path, one directory at a time
+    /// rather than the whole prefix at once. The files come in the same order as a recursive
+    /// listing, and a path naming a file lists just that file.
+    #[async_backtrace::framed]
+    pub async fn list_stream_with_depth(
+        &self,
+        operator: &Operator,
+        thread_num: usize,
+        max_depth: usize,
+    ) -> Result<StageFileInfoStream> {
+        if self.path == STDIN_FD || self.files.is_some() {
+            return self.list_stream(operator, thread_num, None).await;
+        }
+        match operator.stat(&self.path).await {
+            Ok(meta) if meta.is_file() => {
+                let file = StageFileInfo::new(self.path.clone(), &meta);
+                return Ok(Box::pin(stream::once(async { Ok(file) })));
+            }
+            Err(e) if e.kind() != opendal::ErrorKind::NotFound => {
+                return Err(e.into());
+            }
+            _ => {}
+        }
+
+        let pattern = Arc::new(self.get_pattern()?);
+        let prefix_len = if self.path == "/" { 0 } else { self.path.len() };
+        let dir = if self.path.is_empty() {
+            "/".to_string()
+        } else if self.path.ends_with('/') {
+            self.path.clone()
+        } else {
+            format!("{}/", self.path)
+        };
+        let lister = list_dir(operator, &dir).await?;
+        let state = (operator.clone(), vec![(dir, lister, 0)]);
+        let files = stream::try_unfold(state, move |(operator, mut listers)| {
+            let pattern = pattern.clone();
+            async move {
+                // Descend into a directory as soon as it is listed, to keep the key order.
+                while let Some((dir, lister, depth)) = listers.last_mut() {
+                    let depth = *depth;
+                    let Some(entry) = lister.try_next().await? else {
+                        listers.pop();
+                        continue;
+                    };
+                    if entry.path() == dir.as_str() {
+                        continue;
+                    }
+                    let meta = entry.metadata();
+                    if meta.is_dir() {
+                        if depth < max_depth {
+                            let lister = list_dir(&operator, entry.path()).await?;
+                            listers.push((entry.path().to_string(), lister, depth + 1));
+                        }
+                        continue;
+                    }
+                    if check_file(&entry.path()[prefix_len..], meta.mode(), &pattern) {
+                        let file = StageFileInfo::new(entry.path().to_string(), meta);
+                        return Ok(Some((file, (operator, listers))));
+                    }
+                }
+                Ok::<_, ErrorCode>(None)
+            }
+        });
+        Ok(Box::pin(files))
+    }
+
     /// Stat files concurrently.
     #[async_backtrace::framed]
     pub async fn stat_concurrent(
@@ -359,6 +427,145 @@ impl StageFilesInfo {
     }
 }
 
+/// Expand glob-style brace groups in a pattern into regex alternations,
+/// e.g. `data/{2023,2024}/.*` becomes `data/(?:2023|2024)/.*`.
+///
+/// Nested groups are expanded recursively, escaped braces (`\{`) and braces
+/// inside a character class are kept literal. A group holding a repetition
+/// (`{n}`, `{n,}`) right after a repeatable atom is kept as a regex quantifier,
+/// a bounded one (`{n,m}`) only after a character class, a group, `.` or an
+/// escape. So `a{2}` and `[0-9]{2,4}` are unchanged while `/{2023,2024}`,
+/// `year={2023,2024}` and `file_{1,2}` are expanded.
+pub fn expand_pattern_braces(pattern: &str) -> Result<String> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut pos = 0;
+    let mut expanded = String::with_capacity(pattern.len());
+    expand_braces_seq(pattern, &chars, &mut pos, 0, &mut expanded)?;
+    Ok(expanded)
+}
+
+fn expand_braces_seq(
+    pattern: &str,
+    chars: &[char],
+    pos: &mut usize,
+    depth: usize,
+    output: &mut String,
+) -> Result<()> {
+    let start = output.len();
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        match c {
+            '\\' => {
+                output.push(c);
+                *pos += 1;
+                if *pos < chars.len() {
+                    output.push(chars[*pos]);
+                    *pos += 1;
+                }
+            }
+            '[' => {
+                // Copy the character class as is, braces inside are literal.
+                output.push(c);
+                *pos += 1;
+                let mut first = true;
+                while *pos < chars.len() {
+                    let c = chars[*pos];
+                    output.push(c);
+                    *pos += 1;
+                    if c == '\\' && *pos < chars.len() {
+                        output.push(chars[*pos]);
+                        *pos += 1;
+                    } else if c == ']' && !first {
+                        break;
+                    }
+                    first = c == '^' && first;
+                }
+            }
+            '{' => {
+                if let Some(quantifier) = repetition_quantifier(chars, *pos, &output[start..]) {
+                    output.push_str(&quantifier);
+                    *pos += quantifier.chars().count();
+                    continue;
+                }
+                let brace_pos = *pos;
+                *pos += 1;
+                output.push_str("(?:");
+                loop {
+                    expand_braces_seq(pattern, chars, pos, depth + 1, output)?;
+                    match chars.get(*pos) {
+                        Some(',') => {
+                            output.push('|');
+                            *pos += 1;
+                        }
+                        Some('}') => {
+                            output.push(')');
+                            *pos += 1;
+                            break;
+                        }
+                        _ => {
+                            return Err(ErrorCode::SyntaxException(format!(
+                                "Pattern format invalid, got:{}, error: unbalanced brace '{{' at position {}",
+                                pattern, brace_pos
+                            )));
+                        }
+                    }
+                }
+            }
+            '}' | ',' if depth > 0 => return Ok(()),
+            '}' => {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Pattern format invalid, got:{}, error: unbalanced brace '}}' at position {}",
+                    pattern, *pos
+                )));
+            }
+            _ => {
+                output.push(c);
+                *pos += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the brace group at `pos` if it is a regex repetition following a repeatable atom.
+///
+/// A bounded `{n,m}` only counts after a class, group, `.` or escape; otherwise it is a glob.
+fn repetition_quantifier(chars: &[char], pos: usize, preceding: &str) -> Option<String> {
+    match preceding.chars().last() {
+        None | Some('/') | Some('(') | Some('|') => return None,
+        _ => {}
+    }
+    let end = chars[pos..].iter().position(|c| *c == '}')? + pos;
+    let content = chars[pos + 1..end].iter().collect::<String>();
+    let (lower, upper) = content.split_once(',').unwrap_or((content.as_str(), ""));
+    let is_number = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if lower.is_empty() || !is_number(lower) || !is_number(upper) {
+        return None;
+    }
+    if !upper.is_empty() && !follows_class_or_escape(&chars[..pos]) {
+        return None;
+    }
+    Some(chars[pos..=end].iter().collect())
+}
+
+/// Whether the pattern before a brace group ends with a character class, a regex group,
+/// `.` or an escaped character such as `\d`.
+fn follows_class_or_escape(preceding: &[char]) -> bool {
+    match preceding.split_last() {
+        Some((']' | ')' | '.', _)) => true,
+        Some((_, rest)) => rest.iter().rev().take_while(|c| **c == '\\').count() % 2 == 1,
+        None => false,
+    }
+}
+
+async fn list_dir(operator: &Operator, dir: &str) -> Result<Lister> {
+    Ok(operator
+        .lister_with(dir)
+        .recursive(false)
+        .metakey(StageFileInfo::meta_query())
+        .await?)
+}
+
 fn check_file(path: &str, mode: EntryMode, pattern: &Option<Regex>) -> bool {
     if !path.is_empty() && mode.is_file() {
         pattern.as_ref().map_or(true, |p| p.is_match(path))
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_storage::expand_pattern_braces;
+use databend_common_storage::StageFilesInfo;
+use futures::TryStreamExt;
+use opendal::services::Memory;
+use opendal::Operator;
+
+#[test]
+fn test_expand_pattern_braces() {
+    let cases = [
+        ("data/.*[.]parquet", "data/.*[.]parquet"),
+        ("data/{2023,2024}/.*", "data/(?:2023|2024)/.*"),
+        ("{a,b}_{c,d}", "(?:a|b)_(?:c|d)"),
+        // Nested groups.
+        (
+            "data/{2023/{01,02},2024}/.*",
+            "data/(?:2023/(?:01|02)|2024)/.*",
+        ),
+        // Escaped braces and braces in character class are literal.
+        (r"data/\{a,b\}", r"data/\{a,b\}"),
+        ("data/[{}]", "data/[{}]"),
+        // Regex repetitions are kept.
+        ("a{2}", "a{2}"),
+        ("[0-9]{2,4}[.]csv", "[0-9]{2,4}[.]csv"),
+        ("{x{2},y}", "(?:x{2}|y)"),
+        (r"\d{2,4}", r"\d{2,4}"),
+        (".{1,3}", ".{1,3}"),
+        // Bounded groups after a plain character are globs.
+        ("year={2023,2024}/.*", "year=(?:2023|2024)/.*"),
+        ("file_{1,2}[.]parquet", "file_(?:1|2)[.]parquet"),
+        ("a{2,3}", "a(?:2|3)"),
+        ("{a,b}{1,2}", "(?:a|b)(?:1|2)"),
+    ];
+    for (pattern, expected) in cases {
+        assert_eq!(
+            expand_pattern_braces(pattern).unwrap(),
+            expected,
+            "{pattern}"
+        );
+    }
+}
+
+#[test]
+fn test_expand_pattern_braces_unbalanced() {
+    for pattern in ["data/{2023,2024/.*", "data/2023}/.*", "{a,{b,c}"] {
+        let err = expand_pattern_braces(pattern).unwrap_err();
+        assert!(err.message().contains("unbalanced brace"), "{pattern}");
+    }
+}
+
+#[tokio::test]
+async fn test_list_stream_with_depth() -> Result<()> {
+    let operator = Operator::new(Memory::default())?.finish();
+    for path in ["data/a.parquet", "data/b/c.parquet", "data/b/d/e.parquet"] {
+        operator.write(path, vec![0; 8]).await?;
+    }
+
+    let files_info = StageFilesInfo {
+        path: "data/".to_string(),
+        files: None,
+        pattern: None,
+    };
+    for (max_depth, expected) in [
+        (0, vec!["data/a.parquet"]),
+        (1, vec!["data/a.parquet", "data/b/c.parquet"]),
+        (2, vec![
+            "data/a.parquet",
+            "data/b/c.parquet",
+            "data/b/d/e.parquet",
+        ]),
+    ] {
+        let files = files_info
+            .list_stream_with_depth(&operator, 1, max_depth)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        let paths = files
+            .iter()
+            .map(|file| file.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, expected, "max_depth {max_depth}");
+    }
+
+    // The pattern is matched against the path relative to the location.
+    let files_info = StageFilesInfo {
+        pattern: Some("b/.*".to_string()),
+        ..files_info
+    };
+    let files = files_info
+        .list_stream_with_depth(&operator, 1, 2)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    let paths = files
+        .iter()
+        .map(|file| file.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["data/b/c.parquet", "data/b/d/e.parquet"]);
+
+    Ok(())
+}
@@ -126,6 +126,14 @@ pub struct IcebergGlueCatalogOption {
     pub props: HashMap<String, String>,
 }
 
+impl IcebergGlueCatalogOption {
+    /// Merge `overrides` into the props, an overridden key takes the new value
+    /// and the keys that are not overridden are kept.
+    pub fn merge_props(&mut self, overrides: HashMap<String, String>) {
+        self.props.extend(overrides);
+    }
+}
+
 /// Same as `CatalogNameIdent`, but with `serde` support,
 /// and can be used a s part of a value.
 // #[derive(Clone, Debug, PartialEq, Eq)]
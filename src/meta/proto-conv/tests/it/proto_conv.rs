@@ -29,6 +29,7 @@ use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergGlueCatalogOption;
 use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::schema::IndexType;
 use databend_common_meta_app::schema::LockType;
@@ -37,6 +38,7 @@ use databend_common_proto_conv::Incompatible;
 use databend_common_proto_conv::VER;
 use maplit::btreemap;
 use maplit::btreeset;
+use maplit::hashmap;
 use pretty_assertions::assert_eq;
 
 fn s(ss: impl ToString) -> String {
@@ -291,6 +293,32 @@ fn test_pb_from_to() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_merge_glue_catalog_props() -> anyhow::Result<()> {
+    let mut option = IcebergGlueCatalogOption {
+        warehouse: s("s3://my_bucket"),
+        props: hashmap! {
+            s("REGION") => s("us-east-1"),
+            s("AWS_KEY_ID") => s("default key"),
+        },
+    };
+    option.merge_props(hashmap! {
+        s("AWS_KEY_ID") => s("user key"),
+        s("AWS_SECRET_KEY") => s("user secret"),
+    });
+    assert_eq!(option.props, hashmap! {
+        s("REGION") => s("us-east-1"),
+        s("AWS_KEY_ID") => s("user key"),
+        s("AWS_SECRET_KEY") => s("user secret"),
+    });
+
+    let p = option.to_pb()?;
+    let got = IcebergGlueCatalogOption::from_pb(p)?;
+    assert_eq!(option, got);
+
+    Ok(())
+}
+
 #[test]
 fn test_incompatible() -> anyhow::Result<()> {
     let db_meta = new_db_meta();
@@ -142,12 +142,9 @@ impl Partitions {
                 parts.into_iter().map(|x| x.1).collect()
             }
             PartitionsShuffleKind::ConsistentHash => {
-                let mut scale = 0;
-                let num_executors = executors_sorted.len();
-                const EXPECT_NODES: usize = 100;
-                while num_executors << scale < EXPECT_NODES {
-                    scale += 1;
-                }
+                // Each executor owns a fixed number of virtual nodes, so adding or removing
+                // one executor only remaps the partitions that hash into its ranges.
+                const VIRTUAL_NODES_PER_EXECUTOR: i32 = 64;
 
                 let mut executor_part = executors_sorted
                     .iter()
@@ -159,7 +156,7 @@ impl Partitions {
                     .flat_map(|e| {
                         let mut s = DefaultHasher::new();
                         e.hash(&mut s);
-                        (0..1 << scale).map(move |i| {
+                        (0..VIRTUAL_NODES_PER_EXECUTOR).map(move |i| {
                             i.hash(&mut s);
                             (e, s.finish())
                         })
@@ -98,4 +98,22 @@ impl TableArgs {
             Ok(self.named.clone())
         }
     }
+
+    /// Same as [`Self::expect_all_named`], but the error suggests the named form
+    /// using `example_arg`, e.g. `location => ...`.
+    pub fn expect_all_named_like(
+        &self,
+        func_name: &str,
+        example_arg: &str,
+    ) -> Result<HashMap<String, Scalar>> {
+        if !self.positioned.is_empty() {
+            debug!("{:?} accept named args only", self.positioned);
+            Err(ErrorCode::BadArguments(format!(
+                "{} requires named arguments like {} => ..., positional arguments are not supported",
+                func_name, example_arg
+            )))
+        } else {
+            Ok(self.named.clone())
+        }
+    }
 }
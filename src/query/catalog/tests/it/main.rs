@@ -16,3 +16,4 @@
 
 mod partitions;
 mod projection;
+mod table_args;
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::assert_eq;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::hash::Hash;
 use std::hash::Hasher;
 use std::io::Write;
@@ -255,6 +256,49 @@ fn test_partition_reshuffle() {
     }
 }
 
+#[test]
+fn test_partition_reshuffle_consistent_hash_add_executor() {
+    let partitions = gen_parts(PartitionsShuffleKind::ConsistentHash, 200);
+    let executors_3 = vec![
+        "node-1".to_string(),
+        "node-2".to_string(),
+        "node-3".to_string(),
+    ];
+    let mut executors_4 = executors_3.clone();
+    executors_4.push("node-4".to_string());
+
+    let assignment = |executors: Vec<String>| {
+        let mut assignment = HashMap::new();
+        for (executor, parts) in partitions.reshuffle(executors).unwrap() {
+            for part in parts.partitions {
+                assignment.insert(part.hash(), executor.clone());
+            }
+        }
+        assignment
+    };
+
+    let before = assignment(executors_3);
+    let after = assignment(executors_4);
+    assert_eq!(before.len(), 200);
+    assert_eq!(after.len(), 200);
+
+    // Partitions either keep their executor or move to the new one.
+    let mut kept = 0;
+    for (part, executor) in before.iter() {
+        let new_executor = &after[part];
+        if new_executor == executor {
+            kept += 1;
+        } else {
+            assert_eq!(new_executor, "node-4");
+        }
+    }
+    assert!(
+        kept > 100,
+        "only {} of 200 partitions kept their executor",
+        kept
+    );
+}
+
 #[test]
 fn test_split() {
     for seg in 0..1024 * 10 {
@@ -0,0 +1,38 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::ErrorCode;
+use databend_common_expression::Scalar;
+use maplit::hashmap;
+
+#[test]
+fn test_expect_all_named_like() {
+    let args = TableArgs::new_positioned(vec![Scalar::String("@s".to_string())]);
+    let err = args
+        .expect_all_named_like("infer_schema", "location")
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+    assert_eq!(
+        err.message(),
+        "infer_schema requires named arguments like location => ..., positional arguments are not supported"
+    );
+
+    let args = TableArgs::new_named(hashmap! {
+        "location".to_string() => Scalar::String("@s".to_string()),
+    });
+    let named = args
+        .expect_all_named_like("infer_schema", "location")
+        .unwrap();
+    assert_eq!(named.len(), 1);
+}
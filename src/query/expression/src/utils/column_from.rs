@@ -12,10 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::iter::Iterator;
 
+use arrow_array::make_array;
+use arrow_array::Array;
+use arrow_schema::DataType as ArrowDataType;
+use databend_common_column::binview::BinaryViewColumnBuilder;
 use databend_common_column::bitmap::MutableBitmap;
+use databend_common_column::types::months_days_micros;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_io::geometry_from_ewkt;
 use itertools::Itertools;
+use roaring::RoaringTreemap;
 
 use crate::types::decimal::*;
 use crate::types::geometry::GeometryType;
@@ -23,26 +33,32 @@ use crate::types::nullable::NullableColumn;
 use crate::types::number::*;
 use crate::types::*;
 use crate::Column;
+use crate::ColumnBuilder;
+use crate::Scalar;
 
+/// Build a column from any `IntoIterator` of values, e.g. a `Vec`, a range or
+/// an iterator adapter. Types with `Copy` scalars also accept borrowed slices.
 pub trait FromData<D> {
-    fn from_data(_: Vec<D>) -> Column;
+    fn from_data<I: IntoIterator<Item = D>>(_: I) -> Column;
 
-    fn from_data_with_validity(d: Vec<D>, valids: Vec<bool>) -> Column {
+    fn from_data_with_validity<I: IntoIterator<Item = D>>(d: I, valids: Vec<bool>) -> Column {
         let column = Self::from_data(d);
         Column::Nullable(Box::new(NullableColumn::new(column, valids.into())))
     }
 
-    fn from_opt_data(_: Vec<Option<D>>) -> Column;
+    fn from_opt_data<I: IntoIterator<Item = Option<D>>>(_: I) -> Column;
 }
 
 macro_rules! impl_from_data {
     ($T: ident) => {
         impl FromData<<$T as ValueType>::Scalar> for $T {
-            fn from_data(d: Vec<<$T as ValueType>::Scalar>) -> Column {
+            fn from_data<I: IntoIterator<Item = <$T as ValueType>::Scalar>>(d: I) -> Column {
                 $T::upcast_column($T::column_from_iter(d.into_iter(), &[]))
             }
 
-            fn from_opt_data(d: Vec<Option<<$T as ValueType>::Scalar>>) -> Column {
+            fn from_opt_data<I: IntoIterator<Item = Option<<$T as ValueType>::Scalar>>>(
+                d: I,
+            ) -> Column {
                 type NT = NullableType<$T>;
                 NT::upcast_column(NT::column_from_iter(d.into_iter(), &[]))
             }
@@ -67,27 +83,61 @@ impl_from_data! { BinaryType }
 impl_from_data! { StringType }
 impl_from_data! { DateType }
 impl_from_data! { TimestampType }
+impl_from_data! { IntervalType }
 impl_from_data! { VariantType }
 impl_from_data! { BitmapType }
 impl_from_data! { GeometryType }
 impl_from_data! { GeographyType }
 
+macro_rules! impl_from_borrowed_data {
+    ($T: ident) => {
+        impl<'a> FromData<&'a <$T as ValueType>::Scalar> for $T {
+            fn from_data<I: IntoIterator<Item = &'a <$T as ValueType>::Scalar>>(d: I) -> Column {
+                $T::from_data(d.into_iter().copied())
+            }
+
+            fn from_opt_data<I: IntoIterator<Item = Option<&'a <$T as ValueType>::Scalar>>>(
+                d: I,
+            ) -> Column {
+                $T::from_opt_data(d.into_iter().map(|d| d.copied()))
+            }
+        }
+    };
+}
+
+impl_from_borrowed_data! { Int8Type }
+impl_from_borrowed_data! { Int16Type }
+impl_from_borrowed_data! { Int32Type }
+impl_from_borrowed_data! { Int64Type }
+impl_from_borrowed_data! { UInt8Type }
+impl_from_borrowed_data! { UInt16Type }
+impl_from_borrowed_data! { UInt32Type }
+impl_from_borrowed_data! { UInt64Type }
+impl_from_borrowed_data! { Float32Type }
+impl_from_borrowed_data! { Float64Type }
+impl_from_borrowed_data! { Decimal128Type }
+impl_from_borrowed_data! { Decimal256Type }
+impl_from_borrowed_data! { BooleanType }
+impl_from_borrowed_data! { DateType }
+impl_from_borrowed_data! { TimestampType }
+impl_from_borrowed_data! { IntervalType }
+
 impl<'a> FromData<&'a [u8]> for BinaryType {
-    fn from_data(d: Vec<&'a [u8]>) -> Column {
+    fn from_data<I: IntoIterator<Item = &'a [u8]>>(d: I) -> Column {
         BinaryType::from_data(d.into_iter().map(|d| d.to_vec()).collect_vec())
     }
 
-    fn from_opt_data(d: Vec<Option<&'a [u8]>>) -> Column {
+    fn from_opt_data<I: IntoIterator<Item = Option<&'a [u8]>>>(d: I) -> Column {
         BinaryType::from_opt_data(d.into_iter().map(|d| d.map(|d| d.to_vec())).collect_vec())
     }
 }
 
 impl<'a> FromData<&'a str> for StringType {
-    fn from_data(d: Vec<&'a str>) -> Column {
+    fn from_data<I: IntoIterator<Item = &'a str>>(d: I) -> Column {
         StringType::from_data(d.into_iter().map(|d| d.to_string()).collect_vec())
     }
 
-    fn from_opt_data(d: Vec<Option<&'a str>>) -> Column {
+    fn from_opt_data<I: IntoIterator<Item = Option<&'a str>>>(d: I) -> Column {
         StringType::from_opt_data(
             d.into_iter()
                 .map(|d| d.map(|d| d.to_string()))
@@ -97,25 +147,200 @@ impl<'a> FromData<&'a str> for StringType {
 }
 
 impl FromData<f32> for Float32Type {
-    fn from_data(d: Vec<f32>) -> Column {
+    fn from_data<I: IntoIterator<Item = f32>>(d: I) -> Column {
         Float32Type::from_data(d.into_iter().map(F32::from).collect_vec())
     }
 
-    fn from_opt_data(d: Vec<Option<f32>>) -> Column {
+    fn from_opt_data<I: IntoIterator<Item = Option<f32>>>(d: I) -> Column {
         Float32Type::from_opt_data(d.into_iter().map(|d| d.map(F32::from)).collect_vec())
     }
 }
 
 impl FromData<f64> for Float64Type {
-    fn from_data(d: Vec<f64>) -> Column {
+    fn from_data<I: IntoIterator<Item = f64>>(d: I) -> Column {
         Float64Type::from_data(d.into_iter().map(F64::from).collect_vec())
     }
 
-    fn from_opt_data(d: Vec<Option<f64>>) -> Column {
+    fn from_opt_data<I: IntoIterator<Item = Option<f64>>>(d: I) -> Column {
         Float64Type::from_opt_data(d.into_iter().map(|d| d.map(F64::from)).collect_vec())
     }
 }
 
+/// Build interval columns from `(months, days, micros)` triples.
+impl FromData<(i32, i32, i64)> for IntervalType {
+    fn from_data<I: IntoIterator<Item = (i32, i32, i64)>>(d: I) -> Column {
+        IntervalType::from_data(
+            d.into_iter()
+                .map(|(months, days, micros)| months_days_micros::new(months, days, micros))
+                .collect_vec(),
+        )
+    }
+
+    fn from_opt_data<I: IntoIterator<Item = Option<(i32, i32, i64)>>>(d: I) -> Column {
+        IntervalType::from_opt_data(
+            d.into_iter()
+                .map(|d| {
+                    d.map(|(months, days, micros)| months_days_micros::new(months, days, micros))
+                })
+                .collect_vec(),
+        )
+    }
+}
+
+impl BinaryType {
+    /// Build a binary column from fixed-width values such as UUIDs or hashes,
+    /// the width `N` is checked at compile time so every row has the same length.
+    pub fn from_fixed_bytes_data<const N: usize>(d: Vec<[u8; N]>) -> Column {
+        BinaryType::from_data(d.into_iter().map(|d| d.to_vec()).collect_vec())
+    }
+
+    pub fn from_opt_fixed_bytes_data<const N: usize>(d: Vec<Option<[u8; N]>>) -> Column {
+        BinaryType::from_opt_data(d.into_iter().map(|d| d.map(|d| d.to_vec())).collect_vec())
+    }
+}
+
+impl BitmapType {
+    /// Build a bitmap column from sets of integers, each set is serialized
+    /// into the bitmap binary format. An empty set produces an empty bitmap.
+    pub fn from_bitmap_data(d: Vec<Vec<u64>>) -> Column {
+        BitmapType::from_data(d.into_iter().map(serialize_bitmap).collect_vec())
+    }
+
+    pub fn from_opt_bitmap_data(d: Vec<Option<Vec<u64>>>) -> Column {
+        BitmapType::from_opt_data(d.into_iter().map(|d| d.map(serialize_bitmap)).collect_vec())
+    }
+}
+
+fn serialize_bitmap(values: Vec<u64>) -> Vec<u8> {
+    let rb = RoaringTreemap::from_iter(values);
+    let mut buf = Vec::with_capacity(rb.serialized_size());
+    rb.serialize_into(&mut buf)
+        .expect("failed serialize roaring treemap");
+    buf
+}
+
+impl GeometryType {
+    /// Build a geometry column from WKT (or EWKT) strings. Invalid input is an error.
+    pub fn from_wkt_data(d: Vec<&str>) -> Result<Column> {
+        let data = d
+            .into_iter()
+            .map(|d| geometry_from_ewkt(d, None))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GeometryType::from_data(data))
+    }
+
+    pub fn from_opt_wkt_data(d: Vec<Option<&str>>) -> Result<Column> {
+        let data = d
+            .into_iter()
+            .map(|d| d.map(|d| geometry_from_ewkt(d, None)).transpose())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GeometryType::from_opt_data(data))
+    }
+}
+
+impl<K: ValueType, V: ValueType> MapType<K, V> {
+    /// Build a map column from rows of key/value pairs. Duplicate keys in a row are
+    /// kept as-is, and an empty row yields an empty map rather than NULL.
+    pub fn from_map_data<KD, VD>(d: Vec<Vec<(KD, VD)>>) -> Column
+    where
+        K: FromData<KD>,
+        V: FromData<VD>,
+    {
+        let mut offsets = Vec::with_capacity(d.len() + 1);
+        offsets.push(0);
+        let mut keys = Vec::new();
+        let mut values = Vec::new();
+        for row in d {
+            for (k, v) in row {
+                keys.push(k);
+                values.push(v);
+            }
+            offsets.push(keys.len() as u64);
+        }
+        Column::Map(Box::new(ArrayColumn {
+            values: Column::Tuple(vec![K::from_data(keys), V::from_data(values)]),
+            offsets: offsets.into(),
+        }))
+    }
+}
+
+impl StringType {
+    /// Build a string column in which repeated values share one copy of their bytes.
+    /// Each distinct value is pushed once into the data buffers, and every row is a
+    /// view into that pool, the view-column form of dictionary encoding.
+    pub fn from_dictionary_string_data(d: Vec<&str>) -> Column {
+        let mut pool = BinaryViewColumnBuilder::<str>::with_capacity(d.len());
+        let mut dictionary = HashMap::new();
+        let keys = d
+            .into_iter()
+            .map(|value| {
+                *dictionary.entry(value).or_insert_with(|| {
+                    pool.push_value(value);
+                    pool.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let pool = pool.freeze();
+        let views = keys
+            .into_iter()
+            .map(|key| pool.views()[key])
+            .collect::<Vec<_>>();
+        // Every view is copied from `pool`, so it points into the buffers it is paired with.
+        let column = StringColumn::try_new(views.into(), pool.data_buffers().clone())
+            .expect("dictionary views must point into the pool buffers");
+        Column::String(column)
+    }
+}
+
+impl Column {
+    /// Build a nullable column of `len` NULLs, the inner column is filled with defaults.
+    pub fn from_null_data(data_type: &DataType, len: usize) -> Column {
+        ColumnBuilder::repeat_default(&data_type.wrap_nullable(), len).build()
+    }
+
+    /// Build a column of `n` copies of `scalar`, the column type is inferred from the scalar.
+    pub fn constant_from_data(scalar: &Scalar, n: usize) -> Column {
+        let scalar = scalar.as_ref();
+        ColumnBuilder::repeat(&scalar, n, &scalar.infer_data_type()).build()
+    }
+
+    /// Build a column from an Arrow array, taking the column type from the Arrow
+    /// data type. Arrays with a null buffer become nullable columns.
+    pub fn from_arrow_array(array: &dyn Array) -> Result<Column> {
+        let data_type = match array.data_type() {
+            ArrowDataType::Int8 => DataType::Number(NumberDataType::Int8),
+            ArrowDataType::Int16 => DataType::Number(NumberDataType::Int16),
+            ArrowDataType::Int32 => DataType::Number(NumberDataType::Int32),
+            ArrowDataType::Int64 => DataType::Number(NumberDataType::Int64),
+            ArrowDataType::UInt8 => DataType::Number(NumberDataType::UInt8),
+            ArrowDataType::UInt16 => DataType::Number(NumberDataType::UInt16),
+            ArrowDataType::UInt32 => DataType::Number(NumberDataType::UInt32),
+            ArrowDataType::UInt64 => DataType::Number(NumberDataType::UInt64),
+            ArrowDataType::Float32 => DataType::Number(NumberDataType::Float32),
+            ArrowDataType::Float64 => DataType::Number(NumberDataType::Float64),
+            ArrowDataType::Boolean => DataType::Boolean,
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View => {
+                DataType::String
+            }
+            ArrowDataType::Date32 | ArrowDataType::Date64 => DataType::Date,
+            ArrowDataType::Timestamp(_, _) => DataType::Timestamp,
+            ty => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Cannot build a column from arrow data type {:?}",
+                    ty
+                )));
+            }
+        };
+        let data_type = if array.nulls().is_some() {
+            data_type.wrap_nullable()
+        } else {
+            data_type
+        };
+        Column::from_arrow_rs(make_array(array.to_data()), &data_type)
+    }
+}
+
 impl<Num: Decimal> DecimalType<Num> {
     pub fn from_data_with_size<D: AsRef<[Num]>>(d: D, size: DecimalSize) -> Column {
         Num::upcast_column(
@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_array::DurationSecondArray;
+use arrow_array::Int32Array;
+use arrow_array::StringArray;
+use databend_common_expression::types::*;
+use databend_common_expression::Column;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_io::ewkb_to_geo;
+use geo::Geometry;
+use geo::Point;
+use geozero::wkb::Ewkb;
+use roaring::RoaringTreemap;
+
+#[test]
+fn test_from_bitmap_data() {
+    let column = BitmapType::from_opt_bitmap_data(vec![Some(vec![1, 2, 3]), Some(vec![]), None]);
+    assert_eq!(
+        column.data_type(),
+        DataType::Nullable(Box::new(DataType::Bitmap))
+    );
+
+    let column = NullableType::<BitmapType>::try_downcast_column(&column).unwrap();
+    let bitmaps = NullableType::<BitmapType>::iter_column(&column)
+        .map(|v| v.map(|v| RoaringTreemap::deserialize_from(v).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(bitmaps, vec![
+        Some(RoaringTreemap::from_iter([1, 2, 3])),
+        Some(RoaringTreemap::new()),
+        None,
+    ]);
+
+    let column = BitmapType::from_bitmap_data(vec![vec![4, 5], vec![]]);
+    let column = BitmapType::try_downcast_column(&column).unwrap();
+    let bitmaps = BitmapType::iter_column(&column)
+        .map(|v| RoaringTreemap::deserialize_from(v).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(bitmaps, vec![
+        RoaringTreemap::from_iter([4, 5]),
+        RoaringTreemap::new()
+    ]);
+}
+
+#[test]
+fn test_from_interval_data() {
+    let column = IntervalType::from_data(vec![(1, 2, 3), (-1, 0, 1_000_000)]);
+    assert_eq!(column.data_type(), DataType::Interval);
+    let column = IntervalType::try_downcast_column(&column).unwrap();
+    let values = IntervalType::iter_column(&column)
+        .map(|v| (v.months(), v.days(), v.microseconds()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![(1, 2, 3), (-1, 0, 1_000_000)]);
+
+    let column = IntervalType::from_opt_data(vec![Some((0, 7, 0)), None]);
+    assert_eq!(
+        column.data_type(),
+        DataType::Nullable(Box::new(DataType::Interval))
+    );
+    let column = NullableType::<IntervalType>::try_downcast_column(&column).unwrap();
+    let values = NullableType::<IntervalType>::iter_column(&column)
+        .map(|v| v.map(|v| (v.months(), v.days(), v.microseconds())))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some((0, 7, 0)), None]);
+}
+
+#[test]
+fn test_from_wkt_data() {
+    let column = GeometryType::from_wkt_data(vec!["POINT(1 2)", "SRID=4326;POINT(3 4)"]).unwrap();
+    assert_eq!(column.data_type(), DataType::Geometry);
+    let column = GeometryType::try_downcast_column(&column).unwrap();
+    let values = GeometryType::iter_column(&column)
+        .map(|v| ewkb_to_geo(&mut Ewkb(v)).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![
+        (Geometry::Point(Point::new(1.0, 2.0)), None),
+        (Geometry::Point(Point::new(3.0, 4.0)), Some(4326)),
+    ]);
+
+    let column = GeometryType::from_opt_wkt_data(vec![Some("POINT(1 2)"), None]).unwrap();
+    assert_eq!(
+        column.data_type(),
+        DataType::Nullable(Box::new(DataType::Geometry))
+    );
+
+    assert!(GeometryType::from_wkt_data(vec!["POINT(1 2)", "POINT(1"]).is_err());
+}
+
+#[test]
+fn test_from_into_iterator() {
+    assert_eq!(
+        Int32Type::from_data(0..4),
+        Int32Type::from_data(vec![0, 1, 2, 3])
+    );
+
+    let values = [1u64, 2, 3];
+    assert_eq!(
+        UInt64Type::from_data(values.iter().copied()),
+        UInt64Type::from_data(vec![1u64, 2, 3])
+    );
+    assert_eq!(
+        UInt64Type::from_data(&values),
+        UInt64Type::from_data(vec![1u64, 2, 3])
+    );
+
+    assert_eq!(
+        Int32Type::from_data(&[1, 2, 3][..]),
+        Int32Type::from_data(vec![1, 2, 3])
+    );
+    assert_eq!(
+        Int32Type::from_opt_data([Some(&1), None]),
+        Int32Type::from_opt_data(vec![Some(1), None])
+    );
+
+    assert_eq!(
+        StringType::from_opt_data(["a", "b"].into_iter().map(Some)),
+        StringType::from_opt_data(vec![Some("a"), Some("b")])
+    );
+}
+
+#[test]
+fn test_from_map_data() {
+    let column = MapType::<StringType, Int32Type>::from_map_data(vec![
+        vec![("a", 1), ("a", 2)],
+        vec![],
+        vec![("b", 3)],
+    ]);
+    assert_eq!(
+        column.data_type(),
+        DataType::Map(Box::new(DataType::Tuple(vec![
+            DataType::String,
+            DataType::Number(NumberDataType::Int32),
+        ])))
+    );
+
+    // Duplicate keys are preserved and the empty row is an empty map.
+    let map = column.as_map().unwrap();
+    assert_eq!(map.offsets.as_slice(), &[0, 2, 2, 3]);
+    assert_eq!(
+        map.values,
+        Column::Tuple(vec![
+            StringType::from_data(vec!["a", "a", "b"]),
+            Int32Type::from_data(vec![1, 2, 3]),
+        ])
+    );
+}
+
+#[test]
+fn test_from_fixed_bytes_data() {
+    let a = *b"0123456789abcdef";
+    let b = [0xffu8; 16];
+    let column = BinaryType::from_fixed_bytes_data(vec![a, b]);
+    assert_eq!(column, BinaryType::from_data(vec![a.to_vec(), b.to_vec()]));
+
+    let column = BinaryType::from_opt_fixed_bytes_data(vec![Some(a), None, Some(b)]);
+    assert_eq!(
+        column.data_type(),
+        DataType::Nullable(Box::new(DataType::Binary))
+    );
+    let column = NullableType::<BinaryType>::try_downcast_column(&column).unwrap();
+    let values = NullableType::<BinaryType>::iter_column(&column)
+        .map(|v| v.map(|v| <[u8; 16]>::try_from(v).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(a), None, Some(b)]);
+}
+
+#[test]
+fn test_from_dictionary_string_data() {
+    let values = vec![
+        "low cardinality value",
+        "another repeated value",
+        "low cardinality value",
+        "low cardinality value",
+        "another repeated value",
+    ];
+    let column = StringType::from_dictionary_string_data(values.clone());
+    assert_eq!(column, StringType::from_data(values.clone()));
+
+    // Only the two distinct values are stored, every row is a view into them.
+    let strings = column.as_string().unwrap();
+    let distinct_len = "low cardinality value".len() + "another repeated value".len();
+    assert_eq!(strings.total_buffer_len(), distinct_len);
+    assert!(distinct_len < values.iter().map(|v| v.len()).sum::<usize>());
+}
+
+#[test]
+fn test_from_null_data() {
+    let column = Column::from_null_data(&DataType::Number(NumberDataType::Int32), 5);
+    assert_eq!(
+        column.data_type(),
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32)))
+    );
+    assert_eq!(column, Int32Type::from_opt_data(vec![None::<i32>; 5]));
+
+    let column = column.as_nullable().unwrap();
+    assert_eq!(column.len(), 5);
+    assert_eq!(column.validity.true_count(), 0);
+}
+
+#[test]
+fn test_constant_from_data() {
+    let column = Column::constant_from_data(&Scalar::Number(NumberScalar::Int32(7)), 1000);
+    assert_eq!(column, Int32Type::from_data(vec![7; 1000]));
+
+    let column = Column::constant_from_data(&Scalar::String("abc".to_string()), 1000);
+    assert_eq!(column.len(), 1000);
+    assert_eq!(column, StringType::from_data(vec!["abc"; 1000]));
+}
+
+#[test]
+fn test_from_arrow_array() {
+    let array = Int32Array::from(vec![1, 2, 3]);
+    assert_eq!(
+        Column::from_arrow_array(&array).unwrap(),
+        Int32Type::from_data(vec![1, 2, 3])
+    );
+
+    let array = Int32Array::from(vec![Some(1), None]);
+    assert_eq!(
+        Column::from_arrow_array(&array).unwrap(),
+        Int32Type::from_opt_data(vec![Some(1), None])
+    );
+
+    let array = StringArray::from(vec!["a", "bc", ""]);
+    assert_eq!(
+        Column::from_arrow_array(&array).unwrap(),
+        StringType::from_data(vec!["a", "bc", ""])
+    );
+
+    let array = DurationSecondArray::from(vec![1]);
+    let err = Column::from_arrow_array(&array).unwrap_err();
+    assert!(err
+        .message()
+        .contains("Cannot build a column from arrow data type"));
+}
+
+#[test]
+fn test_from_opt_data_stays_nullable() {
+    // Mixed, all-None and all-Some input all build a nullable column.
+    let cases: Vec<Vec<Option<i32>>> = vec![vec![Some(1), None, Some(3)], vec![None, None], vec![
+        Some(1),
+        Some(2),
+    ]];
+    for data in cases {
+        let column = Int32Type::from_opt_data(data.iter().copied());
+        assert_eq!(
+            column.data_type(),
+            DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32)))
+        );
+        assert_eq!(
+            column,
+            Int32Type::from_data_with_validity(
+                data.iter().map(|v| v.unwrap_or_default()),
+                data.iter().map(|v| v.is_some()).collect(),
+            )
+        );
+    }
+
+    let cases: Vec<Vec<Option<f64>>> = vec![vec![Some(1.5), None], vec![None], vec![Some(2.5)]];
+    for data in cases {
+        let column = Float64Type::from_opt_data(data.iter().copied());
+        assert_eq!(
+            column.data_type(),
+            DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64)))
+        );
+        assert_eq!(
+            column,
+            Float64Type::from_data_with_validity(
+                data.iter().map(|v| v.unwrap_or_default()),
+                data.iter().map(|v| v.is_some()).collect(),
+            )
+        );
+    }
+}
@@ -25,6 +25,7 @@ use databend_common_expression::DataBlock;
 extern crate core;
 
 mod block;
+mod column_from;
 mod common;
 mod decimal;
 mod fill_field_default_value;
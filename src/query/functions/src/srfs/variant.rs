@@ -942,7 +942,7 @@ impl FlattenGenerator {
                 UInt64Type::from_opt_data(vec![Some(seq)]),
                 StringType::from_opt_data(vec![None::<&str>]),
                 StringType::from_opt_data(vec![None::<&str>]),
-                UInt64Type::from_opt_data(vec![None]),
+                UInt64Type::from_opt_data(vec![None::<u64>]),
                 VariantType::from_opt_data(vec![None]),
                 VariantType::from_opt_data(vec![None]),
             ];
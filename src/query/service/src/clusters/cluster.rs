@@ -81,6 +81,7 @@ pub trait ClusterHelper {
     fn local_id(&self) -> String;
     fn ordered_index(&self) -> usize;
 
+    /// The nodes of the cluster, ordered by node id whatever order they were discovered in.
     fn get_nodes(&self) -> Vec<Arc<NodeInfo>>;
 
     async fn do_action<T: Serialize + Send + Clone, Res: for<'de> Deserialize<'de> + Send>(
@@ -93,7 +94,8 @@ pub trait ClusterHelper {
 
 #[async_trait::async_trait]
 impl ClusterHelper for Cluster {
-    fn create(nodes: Vec<Arc<NodeInfo>>, local_id: String) -> Arc<Cluster> {
+    fn create(mut nodes: Vec<Arc<NodeInfo>>, local_id: String) -> Arc<Cluster> {
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
         Arc::new(Cluster { local_id, nodes })
     }
 
@@ -117,9 +119,7 @@ impl ClusterHelper for Cluster {
     }
 
     fn ordered_index(&self) -> usize {
-        let mut nodes = self.get_nodes();
-        nodes.sort_by(|a, b| a.id.cmp(&b.id));
-        nodes
+        self.nodes
             .iter()
             .position(|x| x.id == self.local_id)
             .unwrap_or(0)
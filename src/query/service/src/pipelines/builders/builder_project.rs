@@ -77,6 +77,7 @@ impl PipelineBuilder {
                 self.func_ctx.clone(),
                 project_set.projections.clone(),
                 srf_exprs.clone(),
+                project_set.outer,
                 max_block_size,
             )))
         })
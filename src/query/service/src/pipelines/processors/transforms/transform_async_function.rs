@@ -61,7 +61,7 @@ impl TransformAsyncFunction {
     ) -> Result<()> {
         let count = data_block.num_rows() as u64;
         let value = if count == 0 {
-            UInt64Type::from_data(vec![])
+            UInt64Type::from_data(Vec::<u64>::new())
         } else {
             let tenant = self.ctx.get_tenant();
             let catalog = self.ctx.get_default_catalog()?;
@@ -51,6 +51,8 @@ pub struct TransformSRF {
     srf_results: Vec<VecDeque<(Value<AnyType>, usize)>>,
     /// The output number of rows for each input row.
     num_rows: VecDeque<usize>,
+    /// Emit one row padded with NULLs for input rows without SRF output.
+    outer: bool,
     max_block_size: usize,
 }
 
@@ -61,6 +63,7 @@ impl TransformSRF {
         func_ctx: FunctionContext,
         projections: ColumnSet,
         srf_exprs: Vec<Expr>,
+        outer: bool,
         max_block_size: usize,
     ) -> Box<dyn Processor> {
         let srf_results = vec![VecDeque::new(); srf_exprs.len()];
@@ -71,6 +74,7 @@ impl TransformSRF {
             srf_exprs,
             srf_results,
             num_rows: VecDeque::new(),
+            outer,
             max_block_size,
         })
     }
@@ -101,6 +105,13 @@ impl BlockingTransform for TransformSRF {
         debug_assert_eq!(max_nums_per_row.len(), input_num_rows);
         debug_assert!(self.input.is_none());
 
+        if self.outer {
+            // Keep rows without any SRF output, the SRF columns are padded with NULLs below.
+            for num in max_nums_per_row.iter_mut() {
+                *num = (*num).max(1);
+            }
+        }
+
         self.num_rows = VecDeque::from(max_nums_per_row);
         self.input = Some(input.project(&self.projections));
 
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::FileLocation;
 use databend_common_ast::ast::UriLocation;
+use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -25,6 +26,7 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageType;
@@ -35,11 +37,25 @@ use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async_rs;
+use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
+use futures::future;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Operator;
 use opendal::Scheme;
 
 use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
+use crate::table_functions::infer_schema::table_args::SchemaConflict;
+
+/// Deepest level of subdirectories read with `recursive => true`, so that deeply
+/// nested prefixes cannot make the inference read an unbounded tree.
+const MAX_RECURSIVE_DEPTH: usize = 16;
+
+/// Number of candidate files listed before the first `max_file_count` by path are
+/// picked, so that a large stage is not listed in full.
+const LISTING_WINDOW: usize = 1000;
 
 pub(crate) struct ParquetInferSchemaSource {
     is_finished: bool,
@@ -118,20 +134,81 @@ impl AsyncSource for ParquetInferSchemaSource {
         };
         let operator = init_stage_operator(&stage_info)?;
 
-        let first_file = files_info.first_file(&operator).await?;
         let file_format_params = match &self.args_parsed.file_format {
             Some(f) => self.ctx.get_file_format(f).await?,
             None => stage_info.file_format_params.clone(),
         };
         let schema = match file_format_params.get_type() {
             StageFileFormatType::Parquet => {
-                let arrow_schema = read_parquet_schema_async_rs(
-                    &operator,
-                    &first_file.path,
-                    Some(first_file.size),
+                let thread_num = self.ctx.get_settings().get_max_threads()? as usize;
+                // Sort before truncating, so the sampled files do not depend on the order
+                // in which the object store returns them. Only the first LISTING_WINDOW
+                // candidates are listed: the files read are the first ones by path among
+                // those, which on stores listing in key order (such as S3) are the first
+                // ones by path overall. Subdirectories are only listed with `recursive`,
+                // and never below MAX_RECURSIVE_DEPTH. A location naming a single file is
+                // read whatever its name is, and so is every file matching an explicit
+                // pattern.
+                let max_depth = if self.args_parsed.recursive {
+                    MAX_RECURSIVE_DEPTH
+                } else {
+                    0
+                };
+                let has_pattern = files_info.pattern.is_some();
+                let mut files = files_info
+                    .list_stream_with_depth(&operator, thread_num, max_depth)
+                    .await?
+                    .try_filter(|file| {
+                        future::ready(
+                            has_pattern || file.path == path || is_parquet_file(&file.path),
+                        )
+                    })
+                    .take(LISTING_WINDOW.max(self.args_parsed.max_file_count))
+                    .try_collect::<Vec<_>>()
+                    .await?;
+                files.sort_by(|a, b| a.path.cmp(&b.path));
+                files.truncate(self.args_parsed.max_file_count);
+                if self.args_parsed.schema_conflict == SchemaConflict::First {
+                    files.truncate(1);
+                }
+                let paths = files
+                    .iter()
+                    .map(|file| file.path.clone())
+                    .collect::<Vec<_>>();
+
+                let tasks = files.into_iter().map(|file| {
+                    let operator = operator.clone();
+                    async move { read_table_schema(&operator, &file).await }
+                });
+                let schemas = execute_futures_in_parallel(
+                    tasks,
+                    thread_num,
+                    thread_num * 2,
+                    "infer-schema-worker".to_owned(),
                 )
-                .await?;
-                TableSchema::try_from(&arrow_schema)?
+                .await?
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?;
+
+                // The merge is associative, so merging in file order keeps the
+                // result independent of the order in which the reads complete.
+                let mut schemas = schemas.into_iter();
+                let Some(mut schema) = schemas.next() else {
+                    return Err(ErrorCode::BadArguments("no file found"));
+                };
+                for (i, file_schema) in schemas.enumerate() {
+                    if self.args_parsed.schema_conflict == SchemaConflict::Error
+                        && file_schema.fields() != schema.fields()
+                    {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "infer_schema found different schemas in files {} and {}",
+                            paths[0],
+                            paths[i + 1]
+                        )));
+                    }
+                    schema = merge_schema(&schema, &file_schema)?;
+                }
+                schema
             }
             _ => {
                 return Err(ErrorCode::BadArguments(
@@ -163,3 +240,59 @@ impl AsyncSource for ParquetInferSchemaSource {
         Ok(Some(block))
     }
 }
+
+async fn read_table_schema(operator: &Operator, file: &StageFileInfo) -> Result<TableSchema> {
+    let arrow_schema = read_parquet_schema_async_rs(operator, &file.path, Some(file.size)).await?;
+    TableSchema::try_from(&arrow_schema)
+}
+
+/// Merge the schemas of two files, columns are matched by name.
+///
+/// Columns keep the order in which they first appear. A column is reported
+/// nullable if it is nullable in any of the files, or missing from any of them.
+fn merge_schema(schema: &TableSchema, other: &TableSchema) -> Result<TableSchema> {
+    let mut fields = schema.fields().clone();
+    for field in fields.iter_mut() {
+        if !other.fields().iter().any(|f| f.name() == field.name()) {
+            *field = nullable_field(field);
+        }
+    }
+    for other_field in other.fields() {
+        let Some(field) = fields.iter_mut().find(|f| f.name() == other_field.name()) else {
+            fields.push(nullable_field(other_field));
+            continue;
+        };
+        let data_type = field.data_type().remove_recursive_nullable();
+        let other_data_type = other_field.data_type().remove_recursive_nullable();
+        if data_type != other_data_type {
+            return Err(ErrorCode::BadArguments(format!(
+                "infer_schema found conflicting types for column {}: {} and {}",
+                field.name(),
+                data_type.sql_name(),
+                other_data_type.sql_name()
+            )));
+        }
+        if other_field.is_nullable() && !field.is_nullable() {
+            *field = nullable_field(field);
+        }
+    }
+    Ok(TableSchema::new(fields))
+}
+
+fn nullable_field(field: &TableField) -> TableField {
+    TableField::new(field.name(), field.data_type().wrap_nullable())
+}
+
+/// Whether a listed object looks like a parquet file. Markers such as `_SUCCESS`,
+/// hidden files and files of other formats are skipped, files without an
+/// extension are kept.
+fn is_parquet_file(file_path: &str) -> bool {
+    let name = file_path.rsplit('/').next().unwrap_or(file_path);
+    if name.starts_with('_') || name.starts_with('.') {
+        return false;
+    }
+    match name.rsplit_once('.') {
+        Some((_, extension)) => extension.eq_ignore_ascii_case("parquet"),
+        None => true,
+    }
+}
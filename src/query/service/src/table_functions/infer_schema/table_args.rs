@@ -15,24 +15,45 @@
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storage::expand_pattern_braces;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storages_fuse::table_functions::string_value;
 
+/// Number of files infer_schema reads when `max_file_count` is not given.
+pub(crate) const DEFAULT_MAX_FILE_COUNT: usize = 10;
+
+/// How infer_schema handles files whose schemas differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SchemaConflict {
+    /// Union the columns of all files, fail if a column has different types.
+    Merge,
+    /// Fail if any file has a different schema.
+    Error,
+    /// Only use the schema of the first file.
+    First,
+}
+
 #[derive(Clone)]
 pub(crate) struct InferSchemaArgsParsed {
     pub(crate) location: String,
     pub(crate) connection_name: Option<String>,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    pub(crate) max_file_count: usize,
+    pub(crate) recursive: bool,
+    pub(crate) schema_conflict: SchemaConflict,
 }
 
 impl InferSchemaArgsParsed {
     pub(crate) fn parse(table_args: &TableArgs) -> Result<Self> {
-        let args = table_args.expect_all_named("infer_schema")?;
+        let args = table_args.expect_all_named_like("infer_schema", "location")?;
 
         let mut location = None;
         let mut connection_name = None;
         let mut file_format = None;
+        let mut max_file_count = DEFAULT_MAX_FILE_COUNT;
+        let mut recursive = true;
+        let mut schema_conflict = SchemaConflict::Merge;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -48,11 +69,43 @@ impl InferSchemaArgsParsed {
                     connection_name = Some(string_value(v)?);
                 }
                 "pattern" => {
-                    files_info.pattern = Some(string_value(v)?);
+                    // Brace groups are only expanded for infer_schema, other stage
+                    // commands keep matching the pattern as a plain regex.
+                    let pattern = string_value(v)?;
+                    files_info.pattern = Some(expand_pattern_braces(&pattern)?);
                 }
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "max_file_count" => match v.get_i64() {
+                    Some(count) if count > 0 => max_file_count = count as usize,
+                    _ => {
+                        return Err(ErrorCode::BadArguments(
+                            "max_file_count for infer_schema must be a positive integer",
+                        ));
+                    }
+                },
+                "recursive" => match v.as_boolean() {
+                    Some(value) => recursive = *value,
+                    None => {
+                        return Err(ErrorCode::BadArguments(
+                            "recursive for infer_schema must be a boolean",
+                        ));
+                    }
+                },
+                "schema_conflict" => {
+                    schema_conflict = match string_value(v)?.to_lowercase().as_str() {
+                        "merge" => SchemaConflict::Merge,
+                        "error" => SchemaConflict::Error,
+                        "first" => SchemaConflict::First,
+                        other => {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "schema_conflict for infer_schema must be one of 'merge', 'error' or 'first', but got '{}'",
+                                other
+                            )));
+                        }
+                    };
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -64,12 +117,20 @@ impl InferSchemaArgsParsed {
 
         let location = location
             .ok_or_else(|| ErrorCode::BadArguments("infer_schema must specify location"))?;
+        if location.starts_with('@') && connection_name.is_some() {
+            return Err(ErrorCode::BadArguments(
+                "infer_schema connection_name can only be used with a URI location, not a stage",
+            ));
+        }
 
         Ok(Self {
             location,
             connection_name,
             file_format,
             files_info,
+            max_file_count,
+            recursive,
+            schema_conflict,
         })
     }
 }
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_base::base::tokio;
+use databend_common_catalog::cluster_info::Cluster;
 use databend_common_exception::Result;
+use databend_common_meta_types::NodeInfo;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::clusters::ClusterHelper;
 use databend_query::test_kits::*;
@@ -65,6 +69,36 @@ async fn test_remove_invalid_nodes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_cluster_nodes_ordered_by_id() {
+    let node = |id: &str| {
+        Arc::new(NodeInfo::create(
+            id.to_string(),
+            "".to_string(),
+            0,
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ))
+    };
+
+    let ids = |cluster: &Cluster| {
+        cluster
+            .get_nodes()
+            .iter()
+            .map(|node| node.id.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let cluster = Cluster::create(vec![node("b"), node("a"), node("c")], "b".to_string());
+    let reversed = Cluster::create(vec![node("c"), node("b"), node("a")], "b".to_string());
+    assert_eq!(ids(&cluster), vec!["a", "b", "c"]);
+    assert_eq!(ids(&reversed), ids(&cluster));
+    assert_eq!(cluster.ordered_index(), 1);
+    assert_eq!(reversed.ordered_index(), 1);
+}
+
 // TODO:(Winter) need kvapi::KVApi for cluster multiple nodes test
 // #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 // async fn test_multiple_cluster_discovery() -> Result<()> {
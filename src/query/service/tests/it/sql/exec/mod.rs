@@ -37,6 +37,46 @@ pub fn test_format_field_name() {
     assert!(decoded_name == display_name && decoded_index == index);
 }
 
+#[test]
+pub fn test_replace_deep_physical_plan() -> Result<()> {
+    use databend_common_expression::DataSchemaRefExt;
+    use databend_query::sql::executor::physical_plans::ConstantTableScan;
+    use databend_query::sql::executor::physical_plans::Limit;
+    use databend_query::sql::executor::PhysicalPlan;
+    use databend_query::sql::executor::PhysicalPlanReplacer;
+
+    struct Identity;
+    impl PhysicalPlanReplacer for Identity {}
+
+    let depth = 2000;
+    let mut plan = PhysicalPlan::ConstantTableScan(ConstantTableScan {
+        plan_id: 0,
+        values: vec![],
+        num_rows: 0,
+        output_schema: DataSchemaRefExt::create(vec![]),
+    });
+    for _ in 0..depth {
+        plan = PhysicalPlan::Limit(Limit {
+            plan_id: 0,
+            input: Box::new(plan),
+            limit: Some(1),
+            offset: 0,
+            stat_info: None,
+        });
+    }
+
+    let replaced = Identity.replace(&plan)?;
+    let mut node = &replaced;
+    let mut replaced_depth = 0;
+    while let PhysicalPlan::Limit(limit) = node {
+        replaced_depth += 1;
+        node = &limit.input;
+    }
+    assert_eq!(replaced_depth, depth);
+    assert!(matches!(node, PhysicalPlan::ConstantTableScan(_)));
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 pub async fn test_snapshot_consistency() -> Result<()> {
     let fixture = TestFixture::setup().await?;
@@ -13,4 +13,5 @@
 // limitations under the License.
 
 mod binder;
+mod project_set;
 mod select_builder;
@@ -0,0 +1,359 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_ast::Span;
+use databend_common_base::base::tokio;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::optimizer::RelExpr;
+use databend_common_sql::optimizer::SExpr;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::ProjectSet;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::plans::ScalarExpr;
+use databend_common_sql::BindContext;
+use databend_common_sql::Binder;
+use databend_common_sql::IndexType;
+use databend_common_sql::Metadata;
+use databend_common_sql::NameResolutionContext;
+use databend_query::test_kits::TestFixture;
+use parking_lot::RwLock;
+
+async fn bind_sql(ctx: Arc<dyn TableContext>, sql: &str) -> Result<(SExpr, Box<BindContext>)> {
+    let settings = ctx.get_settings();
+    let metadata = Arc::new(RwLock::new(Metadata::default()));
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let binder = Binder::new(
+        ctx.clone(),
+        CatalogManager::instance(),
+        name_resolution_ctx,
+        metadata,
+    );
+    let tokens = tokenize_sql(sql)?;
+    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+    match binder.bind(&stmt).await? {
+        Plan::Query {
+            s_expr,
+            bind_context,
+            ..
+        } => Ok((*s_expr, bind_context)),
+        _ => unreachable!("Query plan expected"),
+    }
+}
+
+fn collect_project_sets(s_expr: &SExpr, project_sets: &mut Vec<ProjectSet>) {
+    if let RelOperator::ProjectSet(project_set) = s_expr.plan() {
+        project_sets.push(project_set.clone());
+    }
+    for child in s_expr.children() {
+        collect_project_sets(child, project_sets);
+    }
+}
+
+fn find_project_sets(s_expr: &SExpr) -> Vec<ProjectSet> {
+    let mut project_sets = vec![];
+    collect_project_sets(s_expr, &mut project_sets);
+    project_sets
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dedup_identical_set_returning_functions() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let (s_expr, bind_context) =
+        bind_sql(ctx.clone(), "SELECT unnest([1, 2, 3]), unnest([1, 2, 3])").await?;
+
+    let project_sets = find_project_sets(&s_expr);
+    assert_eq!(project_sets.len(), 1);
+    assert_eq!(project_sets[0].srfs.len(), 1);
+
+    // Both select items are bound to the same derived column.
+    assert_eq!(bind_context.columns.len(), 2);
+    assert_eq!(bind_context.columns[0].index, project_sets[0].srfs[0].index);
+    assert_eq!(bind_context.columns[1].index, project_sets[0].srfs[0].index);
+
+    Ok(())
+}
+
+fn find_project_set_s_expr(s_expr: &SExpr) -> Option<&SExpr> {
+    if let RelOperator::ProjectSet(_) = s_expr.plan() {
+        return Some(s_expr);
+    }
+    s_expr.children().find_map(find_project_set_s_expr)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_project_set_output_columns() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // The second query has a lazy SRF, it is bound above the `Aggregate`.
+    for sql in [
+        "SELECT number, unnest([1, 2, 3]) FROM numbers(3)",
+        "SELECT number, unnest(array_agg(number)) FROM numbers(3) GROUP BY number",
+    ] {
+        let (s_expr, _) = bind_sql(ctx.clone(), sql).await?;
+        let project_set_s_expr = find_project_set_s_expr(&s_expr).unwrap();
+        let RelOperator::ProjectSet(project_set) = project_set_s_expr.plan() else {
+            unreachable!()
+        };
+        assert_eq!(project_set.srfs.len(), 1, "{sql}");
+        let srf_index = project_set.srfs[0].index;
+
+        // The child columns are all forwarded, plus the one derived SRF column.
+        let output_columns = RelExpr::with_s_expr(project_set_s_expr)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        let mut expected = RelExpr::with_s_expr(project_set_s_expr.child(0)?)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        assert!(!expected.is_empty(), "{sql}");
+        assert!(expected.insert(srf_index), "{sql}");
+        assert_eq!(output_columns, expected, "{sql}");
+    }
+
+    Ok(())
+}
+
+fn collect_column_ref_spans(scalar: &ScalarExpr, index: IndexType, spans: &mut Vec<Span>) {
+    match scalar {
+        ScalarExpr::BoundColumnRef(column_ref) if column_ref.column.index == index => {
+            spans.push(column_ref.span)
+        }
+        ScalarExpr::FunctionCall(func) => {
+            for arg in func.arguments.iter() {
+                collect_column_ref_spans(arg, index, spans);
+            }
+        }
+        ScalarExpr::CastExpr(cast) => collect_column_ref_spans(&cast.argument, index, spans),
+        _ => {}
+    }
+}
+
+fn collect_eval_scalar_items(s_expr: &SExpr, items: &mut Vec<ScalarExpr>) {
+    if let RelOperator::EvalScalar(eval_scalar) = s_expr.plan() {
+        items.extend(eval_scalar.items.iter().map(|item| item.scalar.clone()));
+    }
+    for child in s_expr.children() {
+        collect_eval_scalar_items(child, items);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_returning_function_span() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let sql = "SELECT unnest([1, 2]) + 1";
+    let (s_expr, _) = bind_sql(ctx.clone(), sql).await?;
+
+    let project_sets = find_project_sets(&s_expr);
+    assert_eq!(project_sets.len(), 1);
+    let srf = &project_sets[0].srfs[0];
+    let span = srf.scalar.span().unwrap();
+    assert_eq!(
+        &sql[span.start as usize..span.end as usize],
+        "unnest([1, 2])"
+    );
+
+    // The column reference replacing the SRF keeps the SRF location.
+    let mut items = vec![];
+    collect_eval_scalar_items(&s_expr, &mut items);
+    let mut spans = vec![];
+    for item in items.iter() {
+        collect_column_ref_spans(item, srf.index, &mut spans);
+    }
+    assert_eq!(spans, vec![Some(span)]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_returning_function_error_span() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+    ctx.get_settings().set_setting(
+        "max_set_returning_function_count".to_string(),
+        "1".to_string(),
+    )?;
+
+    // The second SRF exceeds the limit, the error points at it.
+    let sql = "SELECT unnest([1]), unnest([2, 3])";
+    let err = bind_sql(ctx.clone(), sql).await.unwrap_err();
+    let span = err.span().unwrap();
+    assert_eq!(
+        &sql[span.start as usize..span.end as usize],
+        "unnest([2, 3])"
+    );
+
+    // The aggregate rewriter rejects `grouping` outside of grouping sets with an error
+    // without location, it gets the location of the SRF.
+    let sql = "SELECT number, unnest([grouping(number)]) FROM numbers(3)";
+    let err = bind_sql(ctx.clone(), sql).await.unwrap_err();
+    assert!(
+        err.message()
+            .contains("grouping can only be called in GROUP BY GROUPING SETS clauses"),
+        "{err}"
+    );
+    let span = err.span().unwrap();
+    assert_eq!(
+        &sql[span.start as usize..span.end as usize],
+        "unnest([grouping(number)])"
+    );
+
+    Ok(())
+}
+
+fn contains_join(s_expr: &SExpr) -> bool {
+    matches!(s_expr.plan(), RelOperator::Join(_)) || s_expr.children().any(contains_join)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_outer_project_set() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // The flags of the `ProjectSet`s from the top of the plan down.
+    for (sql, outer) in [
+        ("SELECT unnest([1, 2]) FROM numbers(3)", vec![false]),
+        (
+            "SELECT * FROM numbers(3) t, LATERAL unnest([t.number]) f",
+            vec![false],
+        ),
+        (
+            "SELECT * FROM numbers(3) t LEFT JOIN LATERAL unnest([t.number]) f ON true",
+            vec![true],
+        ),
+        (
+            "SELECT * FROM numbers(3) t LEFT JOIN LATERAL unnest([t.number]) f ON true, LATERAL unnest([t.number]) g",
+            vec![false, true],
+        ),
+        (
+            "SELECT unnest([1, 2]) FROM numbers(3) t LEFT JOIN LATERAL unnest([t.number]) f ON true",
+            vec![false, true],
+        ),
+    ] {
+        let (s_expr, _) = bind_sql(ctx.clone(), sql).await?;
+        let project_sets = find_project_sets(&s_expr);
+        let flags = project_sets
+            .iter()
+            .map(|project_set| project_set.outer)
+            .collect::<Vec<_>>();
+        assert_eq!(flags, outer, "{sql}");
+        for project_set in project_sets.iter() {
+            assert_eq!(project_set.srfs.len(), 1, "{sql}");
+        }
+
+        // Lateral SRFs are evaluated on the left rows, there is no join to keep them.
+        assert!(!contains_join(&s_expr), "{sql}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_json_each_columns() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    let sql = r#"SELECT key, value FROM json_each(parse_json('{"a": 1, "b": 2}'))"#;
+    let (s_expr, bind_context) = bind_sql(ctx, sql).await?;
+    let names = bind_context
+        .columns
+        .iter()
+        .map(|column| column.column_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["key", "value"]);
+    assert_ne!(bind_context.columns[0].index, bind_context.columns[1].index);
+    assert_eq!(find_project_sets(&s_expr).len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_project_set_srfs_order() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // The second query only has lazy SRFs, they are bound above the `Aggregate`.
+    for sql in [
+        "SELECT unnest([3]), unnest([1, 2]), unnest([2])",
+        "SELECT unnest(array_agg(number)), unnest([number]), unnest([count()]) FROM numbers(3) GROUP BY number",
+    ] {
+        let mut orders = Vec::new();
+        for _ in 0..5 {
+            let (s_expr, bind_context) = bind_sql(ctx.clone(), sql).await?;
+            let project_sets = find_project_sets(&s_expr);
+            assert_eq!(project_sets.len(), 1, "{sql}");
+
+            // The SRFs keep the order of the select list.
+            let srf_indexes = project_sets[0]
+                .srfs
+                .iter()
+                .map(|srf| srf.index)
+                .collect::<Vec<_>>();
+            let column_indexes = bind_context
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect::<Vec<_>>();
+            assert_eq!(srf_indexes, column_indexes, "{sql}");
+
+            let names = bind_context
+                .columns
+                .iter()
+                .map(|column| column.column_name.clone())
+                .collect::<Vec<_>>();
+            orders.push(names);
+        }
+        assert!(orders.windows(2).all(|w| w[0] == w[1]), "{sql}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_returning_function_display_name_collision() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // Both SRFs are displayed as `unnest(['a', 'b'])`, because string
+    // constants are displayed without escaping the quotes.
+    let sql = "SELECT unnest(['a', 'b']), unnest(['a'', ''b']), unnest(['a'', ''b'])";
+    let (s_expr, bind_context) = bind_sql(ctx, sql).await?;
+    let project_sets = find_project_sets(&s_expr);
+    assert_eq!(project_sets.len(), 1);
+    let srfs = &project_sets[0].srfs;
+    assert_eq!(srfs.len(), 2);
+    assert_ne!(srfs[0].scalar, srfs[1].scalar);
+
+    // The second SRF gets its own column, and its repetition reuses that column.
+    let indexes = bind_context
+        .columns
+        .iter()
+        .map(|column| column.index)
+        .collect::<Vec<_>>();
+    assert_eq!(indexes, [srfs[0].index, srfs[1].index, srfs[1].index]);
+
+    Ok(())
+}
@@ -1703,6 +1703,10 @@ fn project_set_to_format_tree(
             .join(", ")
     ))]);
 
+    if plan.outer {
+        children.push(FormatTreeNode::new("outer: true".to_string()));
+    }
+
     children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
 
     Ok(FormatTreeNode::with_children(
@@ -67,6 +67,7 @@ use crate::executor::physical_plans::Window;
 use crate::executor::physical_plans::WindowPartition;
 
 pub trait PhysicalPlanReplacer {
+    #[recursive::recursive]
     fn replace(&mut self, plan: &PhysicalPlan) -> Result<PhysicalPlan> {
         match plan {
             PhysicalPlan::TableScan(plan) => self.replace_table_scan(plan),
@@ -515,6 +516,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             srf_exprs: plan.srf_exprs.clone(),
             projections: plan.projections.clone(),
+            outer: plan.outer,
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -35,6 +35,8 @@ pub struct ProjectSet {
     pub projections: ColumnSet,
     pub input: Box<PhysicalPlan>,
     pub srf_exprs: Vec<(RemoteExpr, IndexType)>,
+    /// Keep input rows with empty set-returning function output, see [`crate::plans::ProjectSet`].
+    pub outer: bool,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -101,6 +103,7 @@ impl PhysicalPlanBuilder {
             input: Box::new(input),
             srf_exprs,
             projections,
+            outer: project_set.outer,
             stat_info: Some(stat_info),
         }))
     }
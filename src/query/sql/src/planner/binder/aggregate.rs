@@ -733,6 +733,7 @@ impl Binder {
         if collect_grouping_sets {
             grouping_sets.push(Vec::with_capacity(group_by.len()));
         }
+        let max_srf_count = self.max_srf_count()?;
         // Resolve group items with `FROM` context. Since the alias item can not be resolved
         // from the context, we can detect the failure and fallback to resolving with `available_aliases`.
         for expr in group_by.iter() {
@@ -783,7 +784,8 @@ impl Binder {
                 .bind(expr)
                 .or_else(|e| self.resolve_alias_item(bind_context, expr, available_aliases, e))?;
 
-            let mut analyzer = SetReturningAnalyzer::new(bind_context, self.metadata.clone());
+            let mut analyzer =
+                SetReturningAnalyzer::new(bind_context, self.metadata.clone(), max_srf_count);
             analyzer.visit(&mut scalar_expr)?;
 
             if collect_grouping_sets && !grouping_sets.last().unwrap().contains(&scalar_expr) {
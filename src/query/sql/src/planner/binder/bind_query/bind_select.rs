@@ -149,7 +149,7 @@ impl Binder {
 
         // Bind Set-returning functions before filter plan and aggregate plan.
         if !from_context.srf_info.srfs.is_empty() {
-            s_expr = self.bind_project_set(&mut from_context, s_expr, false)?;
+            s_expr = self.bind_project_set(&mut from_context, s_expr, false, false)?;
         }
 
         // To support using aliased column in `WHERE` clause,
@@ -222,7 +222,7 @@ impl Binder {
 
         // Bind lazy Set-returning functions after aggregate plan.
         if !from_context.srf_info.lazy_srf_set.is_empty() {
-            s_expr = self.bind_project_set(&mut from_context, s_expr, true)?;
+            s_expr = self.bind_project_set(&mut from_context, s_expr, true, false)?;
         }
 
         if let Some(qualify) = qualify {
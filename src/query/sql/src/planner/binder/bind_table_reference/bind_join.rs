@@ -20,6 +20,7 @@ use databend_common_ast::ast::split_equivalent_predicate_expr;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::JoinCondition;
 use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::Span;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -78,10 +79,33 @@ impl Binder {
             .add_hash_join_build_cache(cache_column_bindings, cache_column_indexes);
 
         if join.right.is_lateral_table_function() {
+            // `LEFT JOIN LATERAL` keeps the left rows with empty SRF output.
+            let outer = matches!(join.op, JoinOperator::LeftOuter);
+            // The SRF is evaluated on each left row without a join, so there is nothing
+            // to apply another condition to, and the padded rows would be wrong.
+            if outer {
+                let (on_true, span) = match &join.condition {
+                    JoinCondition::On(expr) => (
+                        matches!(**expr, Expr::Literal {
+                            value: Literal::Boolean(true),
+                            ..
+                        }),
+                        expr.span(),
+                    ),
+                    _ => (false, None),
+                };
+                if !on_true {
+                    return Err(ErrorCode::SemanticError(
+                        "LEFT JOIN LATERAL with a table function only supports ON true",
+                    )
+                    .set_span(span));
+                }
+            }
             let (result_expr, bind_context) = self.bind_lateral_table_function(
                 &mut left_context,
                 left_child.clone(),
                 &join.right,
+                outer,
             )?;
             return Ok((result_expr, bind_context));
         }
@@ -314,6 +314,7 @@ impl Binder {
         parent_context: &mut BindContext,
         child: SExpr,
         table_ref: &TableReference,
+        outer: bool,
     ) -> Result<(SExpr, BindContext)> {
         match table_ref {
             TableReference::TableFunction {
@@ -355,7 +356,7 @@ impl Binder {
                     // analyze Set-returning functions.
                     self.analyze_project_set_select(&mut bind_context, &mut select_list)?;
                     // bind Set-returning functions.
-                    let srf_expr = self.bind_project_set(&mut bind_context, child, false)?;
+                    let srf_expr = self.bind_project_set(&mut bind_context, child, false, outer)?;
                     // clear Set-returning functions, avoid duplicate bind.
                     bind_context.srf_info = Default::default();
 
@@ -17,6 +17,8 @@ use std::collections::HashSet;
 use std::mem;
 use std::sync::Arc;
 
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::FunctionKind;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -56,13 +58,20 @@ pub struct SetReturningInfo {
 pub(crate) struct SetReturningAnalyzer<'a> {
     bind_context: &'a mut BindContext,
     metadata: MetadataRef,
+    /// The maximum count of distinct set-returning functions, each of them needs a derived column.
+    max_srf_count: usize,
 }
 
 impl<'a> SetReturningAnalyzer<'a> {
-    pub(crate) fn new(bind_context: &'a mut BindContext, metadata: MetadataRef) -> Self {
+    pub(crate) fn new(
+        bind_context: &'a mut BindContext,
+        metadata: MetadataRef,
+        max_srf_count: usize,
+    ) -> Self {
         Self {
             bind_context,
             metadata,
+            max_srf_count,
         }
     }
 
@@ -79,36 +88,52 @@ impl<'a> VisitorMut<'a> for SetReturningAnalyzer<'a> {
                 .map(|property| property.kind == FunctionKind::SRF)
                 .unwrap_or(false)
             {
+                // Keep the source location of the SRF on the rewritten column references,
+                // so that later errors can point at the SRF in the query text.
+                let span = func.span;
                 let mut replaced_args = Vec::with_capacity(func.arguments.len());
                 for arg in func.arguments.iter() {
                     let mut arg = arg.clone();
                     let mut aggregate_rewriter = self.as_aggregate_rewriter();
-                    aggregate_rewriter.visit(&mut arg)?;
+                    aggregate_rewriter
+                        .visit(&mut arg)
+                        .map_err(|err| fill_span(err, span))?;
                     replaced_args.push(arg);
                 }
 
                 let replaced_expr: ScalarExpr = FunctionCall {
-                    span: func.span,
+                    span,
                     func_name: func.func_name.clone(),
                     params: func.params.clone(),
                     arguments: replaced_args,
                 }
                 .into();
 
-                let srf_display_name = format_scalar(&replaced_expr);
-
                 let srf_info = &mut self.bind_context.srf_info;
+                let srf_display_name = unique_srf_display_name(
+                    srf_info,
+                    format_scalar(&replaced_expr),
+                    &replaced_expr,
+                );
                 if let Some(column_binding) =
                     find_replaced_set_returning_function(srf_info, &srf_display_name)
                 {
                     *expr = BoundColumnRef {
-                        span: None,
+                        span,
                         column: column_binding,
                     }
                     .into();
                     return Ok(());
                 }
 
+                if srf_info.srfs.len() >= self.max_srf_count {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "The number of set-returning functions exceeds the limit: {}, see setting max_set_returning_function_count",
+                        self.max_srf_count
+                    ))
+                    .set_span(span));
+                }
+
                 let index = self.metadata.write().add_derived_column(
                     srf_display_name.clone(),
                     replaced_expr.data_type()?,
@@ -134,7 +159,7 @@ impl<'a> VisitorMut<'a> for SetReturningAnalyzer<'a> {
                 .build();
 
                 *expr = BoundColumnRef {
-                    span: None,
+                    span,
                     column: column_binding,
                 }
                 .into();
@@ -204,6 +229,13 @@ impl<'a> VisitorMut<'a> for SetReturningRewriter<'a> {
 }
 
 impl Binder {
+    pub(crate) fn max_srf_count(&self) -> Result<usize> {
+        Ok(self
+            .ctx
+            .get_settings()
+            .get_max_set_returning_function_count()? as usize)
+    }
+
     /// Analyze project sets in select clause.
     /// See [`SetReturningAnalyzer`] for more details.
     pub(crate) fn analyze_project_set_select(
@@ -211,7 +243,9 @@ impl Binder {
         bind_context: &mut BindContext,
         select_list: &mut SelectList,
     ) -> Result<()> {
-        let mut analyzer = SetReturningAnalyzer::new(bind_context, self.metadata.clone());
+        let max_srf_count = self.max_srf_count()?;
+        let mut analyzer =
+            SetReturningAnalyzer::new(bind_context, self.metadata.clone(), max_srf_count);
         for item in select_list.items.iter_mut() {
             analyzer.visit(&mut item.scalar)?;
         }
@@ -227,17 +261,17 @@ impl Binder {
     ) -> Result<()> {
         let mut srf_info = mem::take(&mut bind_context.srf_info);
         let mut rewriter = SetReturningRewriter::new(bind_context);
-        for srf_item in srf_info.srfs.iter_mut() {
-            let srf_display_name = format_scalar(&srf_item.scalar);
+        for (index, srf_item) in srf_info.srfs.iter_mut().enumerate() {
             rewriter.is_lazy_srf = false;
-            rewriter.visit(&mut srf_item.scalar)?;
+            let span = srf_item.scalar.span();
+            rewriter
+                .visit(&mut srf_item.scalar)
+                .map_err(|err| fill_span(err, span))?;
 
             // If the argument contains aggregation function or group item.
             // add the srf index to lazy set.
             if rewriter.is_lazy_srf {
-                if let Some(index) = srf_info.srfs_map.get(&srf_display_name) {
-                    srf_info.lazy_srf_set.insert(*index);
-                }
+                srf_info.lazy_srf_set.insert(index);
             }
         }
         bind_context.srf_info = srf_info;
@@ -250,6 +284,7 @@ impl Binder {
         bind_context: &mut BindContext,
         child: SExpr,
         is_lazy: bool,
+        outer: bool,
     ) -> Result<SExpr> {
         let srf_len = if is_lazy {
             bind_context.srf_info.lazy_srf_set.len()
@@ -269,13 +304,42 @@ impl Binder {
             }
         }
 
-        let project_set = ProjectSet { srfs };
+        let project_set = ProjectSet { srfs, outer };
         let new_expr = SExpr::create_unary(Arc::new(project_set.into()), Arc::new(child));
 
         Ok(new_expr)
     }
 }
 
+/// Attach the span of a Set-returning function to an error without a location.
+fn fill_span(err: ErrorCode, span: Span) -> ErrorCode {
+    if err.span().is_none() {
+        err.set_span(span)
+    } else {
+        err
+    }
+}
+
+/// Different Set-returning functions can have the same display name, for example string
+/// constants containing quotes are displayed without escaping. Add a suffix to the display name
+/// until it is either unused or belongs to an equal Set-returning function.
+fn unique_srf_display_name(
+    srf_info: &SetReturningInfo,
+    display_name: String,
+    srf: &ScalarExpr,
+) -> String {
+    let mut name = display_name.clone();
+    let mut suffix = 1;
+    while let Some(index) = srf_info.srfs_map.get(&name) {
+        if &srf_info.srfs[*index].scalar == srf {
+            break;
+        }
+        suffix += 1;
+        name = format!("{}_{}", display_name, suffix);
+    }
+    name
+}
+
 /// Replace [`SetReturningFunction`] with a [`ColumnBinding`] if the function is already replaced.
 pub fn find_replaced_set_returning_function(
     srf_info: &SetReturningInfo,
@@ -345,7 +345,13 @@ impl SubqueryRewriter {
             });
         }
         Ok(SExpr::create_unary(
-            Arc::new(ProjectSet { srfs }.into()),
+            Arc::new(
+                ProjectSet {
+                    srfs,
+                    outer: project_set.outer,
+                }
+                .into(),
+            ),
             Arc::new(SExpr::create_unary(
                 Arc::new(
                     EvalScalar {
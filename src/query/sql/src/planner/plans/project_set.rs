@@ -30,6 +30,10 @@ use crate::plans::ScalarItem;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ProjectSet {
     pub srfs: Vec<ScalarItem>,
+    /// If true, an input row for which every set-returning function yields no rows
+    /// is still emitted once, with NULL set-returning function columns.
+    /// This gives `LEFT JOIN LATERAL` semantics, the default drops such rows.
+    pub outer: bool,
 }
 
 impl ProjectSet {